@@ -1,5 +1,6 @@
 use crate::shim::NumberExt;
-use core::ops::{Div, DivAssign, Mul, MulAssign};
+use core::cmp::Ordering;
+use core::ops::{Div, DivAssign, Mul, MulAssign, Neg, Not};
 use Sign::{Negative, Positive, Zero};
 
 /// Contains the sign of a value: positive, negative, or zero.
@@ -33,6 +34,41 @@ impl Default for Sign {
     }
 }
 
+impl Sign {
+    /// The position of the sign on the number line, used to order the variants
+    /// `Negative < Zero < Positive` independently of their declaration order.
+    #[inline(always)]
+    const fn rank(self) -> u8 {
+        match self {
+            Negative => 0,
+            Zero => 1,
+            Positive => 2,
+        }
+    }
+}
+
+impl Ord for Sign {
+    /// Order the signs by their position on the number line, so that
+    /// `Negative < Zero < Positive`.
+    ///
+    /// ```rust
+    /// # use time::Sign;
+    /// assert!(Sign::Negative < Sign::Zero);
+    /// assert!(Sign::Zero < Sign::Positive);
+    /// ```
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Sign {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 macro_rules! sign_mul {
     ($($type:ty),+ $(,)?) => {
         $(
@@ -152,6 +188,26 @@ impl DivAssign<Sign> for Sign {
     }
 }
 
+impl Neg for Sign {
+    type Output = Self;
+
+    /// Return the opposite of the current sign, as [`Sign::negate`].
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl Not for Sign {
+    type Output = Self;
+
+    /// Return the opposite of the current sign, as [`Sign::negate`].
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
 impl Sign {
     /// Return the opposite of the current sign.
     ///
@@ -208,8 +264,323 @@ impl Sign {
     pub const fn is_zero(self) -> bool {
         self as u8 == Zero as u8
     }
+
+    /// Apply the sign to a magnitude, recombining a sign-magnitude pair into a
+    /// signed value. This is exactly `self * magnitude`, but named for intent
+    /// and usable where operator syntax is awkward in generic bounds.
+    ///
+    /// ```rust
+    /// # use time::Sign;
+    /// assert_eq!(Sign::Negative.apply(3), -3);
+    /// assert_eq!(Sign::Positive.apply(3), 3);
+    /// assert_eq!(Sign::Zero.apply(3), 0);
+    /// ```
+    #[inline(always)]
+    pub fn apply<T>(self, magnitude: T) -> T
+    where
+        Sign: Mul<T, Output = T>,
+    {
+        self * magnitude
+    }
+
+    /// Split a value into its [`Sign`] and its magnitude, the inverse of
+    /// [`apply`](Self::apply): `let (s, m) = Sign::split(v); s.apply(m) == v`.
+    ///
+    /// The magnitude is `value.sign() * value`, i.e. the absolute value. For
+    /// the most negative integer (`i*::MIN`) the magnitude cannot be
+    /// represented and negation overflows, exactly as [`i32::abs`] does. For
+    /// floats, `-0.0` splits to `(Negative, 0.0)` and `NaN` — which has no
+    /// meaningful sign — splits to `(Zero, 0.0)`.
+    ///
+    /// ```rust
+    /// # use time::Sign;
+    /// assert_eq!(Sign::split(-3), (Sign::Negative, 3));
+    /// assert_eq!(Sign::split(3), (Sign::Positive, 3));
+    /// assert_eq!(Sign::split(0), (Sign::Zero, 0));
+    /// ```
+    #[inline(always)]
+    pub fn split<T>(value: T) -> (Sign, T)
+    where
+        T: HasSign + Copy,
+        Sign: Mul<T, Output = T>,
+    {
+        let sign = value.sign();
+        (sign, sign * value)
+    }
+}
+
+/// The sign of a value that is known to never be zero: positive or negative.
+///
+/// `NonZeroSign` mirrors [`Sign`] for contexts — ratio sign fields, division
+/// results, the signum of a nonzero quantity — where the `Zero` arm is
+/// statically impossible and would only produce dead branches. Unlike `Sign`,
+/// its multiplication table has no zero row or column, so it is closed under
+/// [`Mul`] and [`Div`]: the sign can be carried through arithmetic without any
+/// spurious zero handling. Convert to `Sign` infallibly with [`From`], and back
+/// with [`TryFrom`] (which fails on [`Sign::Zero`]).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum NonZeroSign {
+    /// A positive value.
+    Positive,
+
+    /// A negative value.
+    Negative,
+}
+
+/// The error returned when converting [`Sign::Zero`] to a [`NonZeroSign`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TryFromSignError;
+
+impl From<NonZeroSign> for Sign {
+    /// Widen a `NonZeroSign` to the corresponding `Sign`. This conversion is
+    /// total: every `NonZeroSign` has a `Sign`.
+    ///
+    /// ```rust
+    /// # use time::{NonZeroSign, Sign};
+    /// assert_eq!(Sign::from(NonZeroSign::Positive), Sign::Positive);
+    /// assert_eq!(Sign::from(NonZeroSign::Negative), Sign::Negative);
+    /// ```
+    #[inline(always)]
+    fn from(value: NonZeroSign) -> Self {
+        match value {
+            NonZeroSign::Positive => Positive,
+            NonZeroSign::Negative => Negative,
+        }
+    }
+}
+
+impl TryFrom<Sign> for NonZeroSign {
+    type Error = TryFromSignError;
+
+    /// Narrow a `Sign` to a `NonZeroSign`, failing on [`Sign::Zero`].
+    ///
+    /// ```rust
+    /// # use time::{NonZeroSign, Sign};
+    /// assert_eq!(NonZeroSign::try_from(Sign::Positive), Ok(NonZeroSign::Positive));
+    /// assert_eq!(NonZeroSign::try_from(Sign::Negative), Ok(NonZeroSign::Negative));
+    /// assert!(NonZeroSign::try_from(Sign::Zero).is_err());
+    /// ```
+    #[inline(always)]
+    fn try_from(value: Sign) -> Result<Self, Self::Error> {
+        match value {
+            Positive => Ok(Self::Positive),
+            Negative => Ok(Self::Negative),
+            Zero => Err(TryFromSignError),
+        }
+    }
+}
+
+macro_rules! nonzero_sign_mul {
+    ($($type:ty),+ $(,)?) => {
+        $(
+            impl Mul<$type> for NonZeroSign {
+                type Output = $type;
+
+                /// Negate the sign of the provided number if `self == NonZeroSign::Negative`.
+                #[inline(always)]
+                fn mul(self, rhs: $type) -> Self::Output {
+                    match self {
+                        Self::Positive => rhs,
+                        Self::Negative => -rhs,
+                    }
+                }
+            }
+
+            impl Mul<NonZeroSign> for $type {
+                type Output = Self;
+
+                /// Negate the sign of the provided number if `rhs == NonZeroSign::Negative`.
+                #[inline(always)]
+                fn mul(self, rhs: NonZeroSign) -> Self::Output {
+                    match rhs {
+                        NonZeroSign::Positive => self,
+                        NonZeroSign::Negative => -self,
+                    }
+                }
+            }
+
+            impl MulAssign<NonZeroSign> for $type {
+                /// Negate the sign of the provided number if `rhs == NonZeroSign::Negative`.
+                #[inline(always)]
+                fn mul_assign(&mut self, rhs: NonZeroSign) {
+                    if rhs.is_negative() {
+                        *self = -*self;
+                    }
+                }
+            }
+
+            impl Div<NonZeroSign> for $type {
+                type Output = Self;
+
+                /// Negate the sign of the provided number if `rhs == NonZeroSign::Negative`.
+                #[inline(always)]
+                fn div(self, rhs: NonZeroSign) -> Self::Output {
+                    self * rhs
+                }
+            }
+
+            impl DivAssign<NonZeroSign> for $type {
+                /// Negate the sign of the provided number if `rhs == NonZeroSign::Negative`.
+                #[inline(always)]
+                fn div_assign(&mut self, rhs: NonZeroSign) {
+                    *self *= rhs
+                }
+            }
+        )*
+    };
+}
+nonzero_sign_mul![i8, i16, i32, i64, i128, f32, f64];
+
+impl Mul<NonZeroSign> for NonZeroSign {
+    type Output = Self;
+
+    /// Multiplying signs follows how signs interact with real numbers. As
+    /// neither operand can be zero, the result is never zero either.
+    ///
+    /// - If the left and right are the same, the result is `NonZeroSign::Positive`.
+    /// - Otherwise, the result is `NonZeroSign::Negative`.
+    ///
+    /// |          | Negative | Positive |
+    /// |----------|----------|----------|
+    /// | Negative | Positive | Negative |
+    /// | Positive | Negative | Positive |
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::Positive, Self::Positive) | (Self::Negative, Self::Negative) => Self::Positive,
+            (Self::Positive, Self::Negative) | (Self::Negative, Self::Positive) => Self::Negative,
+        }
+    }
+}
+
+impl MulAssign<NonZeroSign> for NonZeroSign {
+    /// Multiplying signs follows how signs interact with real numbers.
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
 }
 
+impl Div<NonZeroSign> for NonZeroSign {
+    type Output = Self;
+
+    /// Dividing signs follows how signs interact with real numbers.
+    ///
+    /// |          | Negative | Positive |
+    /// |----------|----------|----------|
+    /// | Negative | Positive | Negative |
+    /// | Positive | Negative | Positive |
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs
+    }
+}
+
+impl DivAssign<NonZeroSign> for NonZeroSign {
+    /// Dividing signs follows how signs interact with real numbers.
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs
+    }
+}
+
+impl NonZeroSign {
+    /// Return the opposite of the current sign.
+    ///
+    /// ```rust
+    /// # use time::NonZeroSign;
+    /// assert_eq!(NonZeroSign::Positive.negate(), NonZeroSign::Negative);
+    /// assert_eq!(NonZeroSign::Negative.negate(), NonZeroSign::Positive);
+    /// ```
+    #[inline(always)]
+    pub fn negate(self) -> Self {
+        match self {
+            Self::Positive => Self::Negative,
+            Self::Negative => Self::Positive,
+        }
+    }
+
+    /// Is the sign positive?
+    ///
+    /// ```rust
+    /// # use time::NonZeroSign;
+    /// assert!(NonZeroSign::Positive.is_positive());
+    /// assert!(!NonZeroSign::Negative.is_positive());
+    /// ```
+    #[inline(always)]
+    pub const fn is_positive(self) -> bool {
+        self as u8 == Self::Positive as u8
+    }
+
+    /// Is the sign negative?
+    ///
+    /// ```rust
+    /// # use time::NonZeroSign;
+    /// assert!(!NonZeroSign::Positive.is_negative());
+    /// assert!(NonZeroSign::Negative.is_negative());
+    /// ```
+    #[inline(always)]
+    pub const fn is_negative(self) -> bool {
+        self as u8 == Self::Negative as u8
+    }
+}
+
+/// Extract the [`Sign`] of a numeric value.
+///
+/// This closes the loop with the `Sign * number` impls: `x.sign() * y` carries
+/// the sign of `x` onto `y`, and `x.sign() * x.abs()` reconstructs `x`.
+pub trait HasSign {
+    /// Return the sign of the value.
+    fn sign(&self) -> Sign;
+}
+
+macro_rules! int_has_sign {
+    ($($type:ty),+ $(,)?) => {
+        $(
+            impl HasSign for $type {
+                /// Return `Positive` when greater than zero, `Negative` when less
+                /// than zero, and `Zero` otherwise.
+                #[inline(always)]
+                fn sign(&self) -> Sign {
+                    if *self > 0 {
+                        Positive
+                    } else if *self < 0 {
+                        Negative
+                    } else {
+                        Zero
+                    }
+                }
+            }
+        )*
+    };
+}
+int_has_sign![i8, i16, i32, i64, i128];
+
+macro_rules! float_has_sign {
+    ($($type:ty),+ $(,)?) => {
+        $(
+            impl HasSign for $type {
+                /// Return the sign following IEEE conventions. `+0.0` and
+                /// `+INFINITY` are `Positive`, `-0.0` and `NEG_INFINITY` are
+                /// `Negative` (the zeroes are distinguished by their sign bit).
+                /// `NaN` has no meaningful sign and is reported as `Zero`.
+                #[inline(always)]
+                fn sign(&self) -> Sign {
+                    if self.is_nan() {
+                        Zero
+                    } else if self.is_sign_negative() {
+                        Negative
+                    } else {
+                        Positive
+                    }
+                }
+            }
+        )*
+    };
+}
+float_has_sign![f32, f64];
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -302,6 +673,32 @@ mod test {
         assert_eq!(Zero.negate(), Zero);
     }
 
+    #[test]
+    fn ord() {
+        assert!(Negative < Zero);
+        assert!(Zero < Positive);
+        assert!(Negative < Positive);
+        assert_eq!(Positive.cmp(&Positive), Ordering::Equal);
+
+        let mut v = [Positive, Negative, Zero];
+        v.sort();
+        assert_eq!(v, [Negative, Zero, Positive]);
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!(-Positive, Negative);
+        assert_eq!(-Negative, Positive);
+        assert_eq!(-Zero, Zero);
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(!Positive, Negative);
+        assert_eq!(!Negative, Positive);
+        assert_eq!(!Zero, Zero);
+    }
+
     #[test]
     fn is_positive() {
         assert!(Positive.is_positive());
@@ -322,4 +719,144 @@ mod test {
         assert!(!Negative.is_zero());
         assert!(Zero.is_zero());
     }
+
+    #[test]
+    fn nonzero_sign_from() {
+        assert_eq!(Sign::from(NonZeroSign::Positive), Positive);
+        assert_eq!(Sign::from(NonZeroSign::Negative), Negative);
+    }
+
+    #[test]
+    fn nonzero_sign_try_from() {
+        assert_eq!(NonZeroSign::try_from(Positive), Ok(NonZeroSign::Positive));
+        assert_eq!(NonZeroSign::try_from(Negative), Ok(NonZeroSign::Negative));
+        assert_eq!(NonZeroSign::try_from(Zero), Err(TryFromSignError));
+    }
+
+    #[test]
+    fn nonzero_sign_mul_int() {
+        assert_eq!(NonZeroSign::Positive * 2, 2);
+        assert_eq!(NonZeroSign::Negative * 2, -2);
+        assert_eq!(2 * NonZeroSign::Positive, 2);
+        assert_eq!(2 * NonZeroSign::Negative, -2);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn nonzero_sign_mul_float() {
+        assert_eq!(NonZeroSign::Positive * 2., 2.);
+        assert_eq!(NonZeroSign::Negative * 2., -2.);
+    }
+
+    #[test]
+    fn nonzero_sign_mul_div_assign_int() {
+        let mut v = 2;
+        v *= NonZeroSign::Negative;
+        assert_eq!(v, -2);
+        v /= NonZeroSign::Negative;
+        assert_eq!(v, 2);
+    }
+
+    #[test]
+    fn nonzero_sign_mul_sign() {
+        assert_eq!(NonZeroSign::Positive * NonZeroSign::Positive, NonZeroSign::Positive);
+        assert_eq!(NonZeroSign::Positive * NonZeroSign::Negative, NonZeroSign::Negative);
+        assert_eq!(NonZeroSign::Negative * NonZeroSign::Positive, NonZeroSign::Negative);
+        assert_eq!(NonZeroSign::Negative * NonZeroSign::Negative, NonZeroSign::Positive);
+    }
+
+    #[test]
+    #[allow(clippy::eq_op)]
+    fn nonzero_sign_div_sign() {
+        assert_eq!(NonZeroSign::Positive / NonZeroSign::Positive, NonZeroSign::Positive);
+        assert_eq!(NonZeroSign::Positive / NonZeroSign::Negative, NonZeroSign::Negative);
+        assert_eq!(NonZeroSign::Negative / NonZeroSign::Positive, NonZeroSign::Negative);
+        assert_eq!(NonZeroSign::Negative / NonZeroSign::Negative, NonZeroSign::Positive);
+    }
+
+    #[test]
+    fn nonzero_sign_mul_div_assign_sign() {
+        let mut v = NonZeroSign::Positive;
+        v *= NonZeroSign::Negative;
+        assert_eq!(v, NonZeroSign::Negative);
+        v /= NonZeroSign::Negative;
+        assert_eq!(v, NonZeroSign::Positive);
+    }
+
+    #[test]
+    fn nonzero_sign_negate() {
+        assert_eq!(NonZeroSign::Positive.negate(), NonZeroSign::Negative);
+        assert_eq!(NonZeroSign::Negative.negate(), NonZeroSign::Positive);
+    }
+
+    #[test]
+    fn nonzero_sign_is_positive() {
+        assert!(NonZeroSign::Positive.is_positive());
+        assert!(!NonZeroSign::Negative.is_positive());
+    }
+
+    #[test]
+    fn nonzero_sign_is_negative() {
+        assert!(!NonZeroSign::Positive.is_negative());
+        assert!(NonZeroSign::Negative.is_negative());
+    }
+
+    #[test]
+    fn int_has_sign() {
+        assert_eq!(1_i32.sign(), Positive);
+        assert_eq!((-1_i32).sign(), Negative);
+        assert_eq!(0_i32.sign(), Zero);
+        assert_eq!(i64::MIN.sign(), Negative);
+        assert_eq!(i8::MAX.sign(), Positive);
+    }
+
+    #[test]
+    fn float_has_sign() {
+        assert_eq!(1.0_f64.sign(), Positive);
+        assert_eq!((-1.0_f64).sign(), Negative);
+        assert_eq!(0.0_f64.sign(), Positive);
+        assert_eq!((-0.0_f64).sign(), Negative);
+        assert_eq!(f64::INFINITY.sign(), Positive);
+        assert_eq!(f64::NEG_INFINITY.sign(), Negative);
+        assert_eq!(f64::NAN.sign(), Zero);
+        assert_eq!(f32::NAN.sign(), Zero);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn apply() {
+        assert_eq!(Negative.apply(3), -3);
+        assert_eq!(Positive.apply(3), 3);
+        assert_eq!(Zero.apply(3), 0);
+        assert_eq!(Negative.apply(3.), -3.);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn split() {
+        assert_eq!(Sign::split(-3), (Negative, 3));
+        assert_eq!(Sign::split(3), (Positive, 3));
+        assert_eq!(Sign::split(0), (Zero, 0));
+        assert_eq!(Sign::split(-2.5_f64), (Negative, 2.5));
+        assert_eq!(Sign::split(-0.0_f64), (Negative, 0.0));
+        assert_eq!(Sign::split(f64::NAN), (Zero, 0.0));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn split_apply_round_trips() {
+        for v in [-7_i32, 0, 7] {
+            let (s, m) = Sign::split(v);
+            assert_eq!(s.apply(m), v);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn has_sign_round_trips() {
+        assert_eq!(5_i32.sign() * 5_i32.abs(), 5);
+        assert_eq!((-5_i32).sign() * (-5_i32).abs(), -5);
+        assert_eq!(5.0_f64.sign() * 5.0_f64.abs(), 5.0);
+        assert_eq!((-5.0_f64).sign() * (-5.0_f64).abs(), -5.0);
+    }
 }